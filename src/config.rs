@@ -0,0 +1,154 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::rules::Severity;
+use std::collections::HashMap;
+use swc_common::BytePos;
+
+/// The severity a user's config file can assign to a rule. Unlike
+/// `Severity`, this also allows turning a rule off entirely.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigLevel {
+  Error,
+  Warn,
+  Off,
+}
+
+impl ConfigLevel {
+  fn to_severity(self) -> Option<Severity> {
+    match self {
+      ConfigLevel::Error => Some(Severity::Error),
+      ConfigLevel::Warn => Some(Severity::Warning),
+      ConfigLevel::Off => None,
+    }
+  }
+}
+
+/// A `// deno-lint-level <code>:<level>` comment found in the source,
+/// together with the byte offset it applies from. Inline directives only
+/// affect diagnostics raised at or after that offset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InlineDirective {
+  pub code: String,
+  pub level: ConfigLevel,
+  pub from: BytePos,
+}
+
+/// Resolves the effective severity of a rule's diagnostics, combining a
+/// config-file level map with inline `// deno-lint-level` directives.
+/// Inline directives win over the config map, which wins over the rule's
+/// own `default_severity`.
+#[derive(Clone, Debug, Default)]
+pub struct LintConfig {
+  levels: HashMap<String, ConfigLevel>,
+  inline: Vec<InlineDirective>,
+}
+
+impl LintConfig {
+  pub fn new(levels: HashMap<String, ConfigLevel>) -> Self {
+    Self {
+      levels,
+      inline: Vec::new(),
+    }
+  }
+
+  /// Scans `source` for `// deno-lint-level <code>:<level>` comments and
+  /// records them as directives effective from that point onward.
+  pub fn with_inline_directives(mut self, source: &str, base: BytePos) -> Self {
+    let mut offset = 0usize;
+    for line in source.split_inclusive('\n') {
+      if let Some(rest) = line.trim_start().strip_prefix("// deno-lint-level ") {
+        if let Some((code, level)) = rest.trim_end().split_once(':') {
+          let level = match level {
+            "error" => Some(ConfigLevel::Error),
+            "warn" => Some(ConfigLevel::Warn),
+            "off" => Some(ConfigLevel::Off),
+            _ => None,
+          };
+          if let Some(level) = level {
+            self.inline.push(InlineDirective {
+              code: code.to_string(),
+              level,
+              from: BytePos(base.0 + (offset + line.len()) as u32),
+            });
+          }
+        }
+      }
+      offset += line.len();
+    }
+    self
+  }
+
+  pub fn effective_severity(
+    &self,
+    code: &str,
+    span_lo: BytePos,
+    default: Severity,
+  ) -> Option<Severity> {
+    if let Some(directive) = self
+      .inline
+      .iter()
+      .filter(|d| d.code == code && d.from <= span_lo)
+      .last()
+    {
+      return directive.level.to_severity();
+    }
+
+    match self.levels.get(code) {
+      Some(level) => level.to_severity(),
+      None => Some(default),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn config_map_overrides_default_severity() {
+    let mut levels = HashMap::new();
+    levels.insert("no-redeclare".to_string(), ConfigLevel::Warn);
+    let config = LintConfig::new(levels);
+    assert_eq!(
+      config.effective_severity("no-redeclare", BytePos(0), Severity::Error),
+      Some(Severity::Warning)
+    );
+  }
+
+  #[test]
+  fn config_map_off_drops_the_diagnostic() {
+    let mut levels = HashMap::new();
+    levels.insert("no-redeclare".to_string(), ConfigLevel::Off);
+    let config = LintConfig::new(levels);
+    assert_eq!(
+      config.effective_severity("no-redeclare", BytePos(0), Severity::Error),
+      None
+    );
+  }
+
+  #[test]
+  fn unconfigured_rule_keeps_its_default_severity() {
+    let config = LintConfig::default();
+    assert_eq!(
+      config.effective_severity("no-redeclare", BytePos(0), Severity::Error),
+      Some(Severity::Error)
+    );
+  }
+
+  #[test]
+  fn inline_directive_only_applies_from_its_own_line_onward() {
+    let source = "var a;\n// deno-lint-level no-redeclare:off\nvar b;\n";
+    let config = LintConfig::default().with_inline_directives(source, BytePos(1));
+
+    let before_directive = BytePos(1);
+    assert_eq!(
+      config.effective_severity("no-redeclare", before_directive, Severity::Error),
+      Some(Severity::Error)
+    );
+
+    let after_directive = BytePos(1 + source.len() as u32 - 1);
+    assert_eq!(
+      config.effective_severity("no-redeclare", after_directive, Severity::Error),
+      None
+    );
+  }
+}
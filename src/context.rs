@@ -0,0 +1,102 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::config::LintConfig;
+use crate::diagnostic::{LintDiagnostic, LintFix};
+use crate::rules::Severity;
+use std::collections::HashMap;
+use swc_common::Span;
+
+/// Per-file state threaded through every `LintRule::lint_program` call.
+/// Rules never construct `LintDiagnostic` directly; they go through one of
+/// the `add_diagnostic*` helpers so this is the one place that knows how to
+/// assemble a diagnostic from a rule's code/message/span, and the one place
+/// that resolves its effective severity.
+pub struct Context {
+  file_name: String,
+  diagnostics: Vec<LintDiagnostic>,
+  config: LintConfig,
+  default_severities: HashMap<&'static str, Severity>,
+}
+
+impl Context {
+  pub fn new(
+    file_name: String,
+    config: LintConfig,
+    default_severities: HashMap<&'static str, Severity>,
+  ) -> Self {
+    Self {
+      file_name,
+      diagnostics: Vec::new(),
+      config,
+      default_severities,
+    }
+  }
+
+  pub fn file_name(&self) -> &str {
+    &self.file_name
+  }
+
+  pub fn diagnostics(&self) -> &[LintDiagnostic] {
+    &self.diagnostics
+  }
+
+  pub fn add_diagnostic(&mut self, span: Span, code: &'static str, message: &str) {
+    self.add_diagnostic_inner(span, code, message, Vec::new(), Vec::new());
+  }
+
+  pub fn add_diagnostic_with_fixes(
+    &mut self,
+    span: Span,
+    code: &'static str,
+    message: &str,
+    fixes: Vec<LintFix>,
+  ) {
+    self.add_diagnostic_inner(span, code, message, Vec::new(), fixes);
+  }
+
+  /// Like `add_diagnostic`, but with secondary `(span, label)` pairs
+  /// pointing at related locations, e.g. the original declaration a
+  /// redeclaration conflicts with.
+  pub fn add_diagnostic_with_hint(
+    &mut self,
+    span: Span,
+    code: &'static str,
+    message: &str,
+    labels: Vec<(Span, String)>,
+  ) {
+    self.add_diagnostic_inner(span, code, message, labels, Vec::new());
+  }
+
+  // Note: severity resolution (including "off") happens here, after the
+  // rule has already produced the diagnostic — a rule set to "off" still
+  // runs its full traversal, we just discard what it reports. Skipping the
+  // traversal itself would need `lint_program` to consult the config before
+  // visiting, which none of today's rules do.
+  fn add_diagnostic_inner(
+    &mut self,
+    span: Span,
+    code: &'static str,
+    message: &str,
+    labels: Vec<(Span, String)>,
+    fixes: Vec<LintFix>,
+  ) {
+    let default = self
+      .default_severities
+      .get(code)
+      .copied()
+      .unwrap_or(Severity::Warning);
+    let severity = match self.config.effective_severity(code, span.lo(), default) {
+      Some(severity) => severity,
+      None => return,
+    };
+
+    self.diagnostics.push(LintDiagnostic {
+      range: span,
+      filename: self.file_name.clone(),
+      code: code.to_string(),
+      message: message.to_string(),
+      severity,
+      labels,
+      fixes,
+    });
+  }
+}
@@ -0,0 +1,51 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::rules::Severity;
+use swc_common::Span;
+
+/// A single text replacement. Spans are byte offsets into the original
+/// source as tracked by the `SourceMap`, so a fix stays valid even if other
+/// fixes are applied earlier in the same pass.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextChange {
+  pub span: Span,
+  pub new_text: String,
+}
+
+/// A named set of `TextChange`s that together resolve a diagnostic. A
+/// diagnostic can carry more than one `LintFix`, but each one is a complete
+/// *alternative* resolution on its own (rust-analyzer-assist style) — they
+/// are never meant to be applied together. See `crate::fixer::apply_fixes`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintFix {
+  pub description: String,
+  pub changes: Vec<TextChange>,
+}
+
+/// A diagnostic produced by a `LintRule`, plus everything needed to render
+/// and (optionally) auto-fix it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintDiagnostic {
+  pub range: Span,
+  pub filename: String,
+  pub code: String,
+  pub message: String,
+  pub severity: Severity,
+  /// Secondary spans called out in the diagnostic, each with a short label
+  /// explaining why it's relevant (e.g. pointing at the original binding
+  /// a redeclaration conflicts with).
+  pub labels: Vec<(Span, String)>,
+  pub fixes: Vec<LintFix>,
+}
+
+impl LintDiagnostic {
+  pub fn display(&self) -> String {
+    let level = match self.severity {
+      Severity::Error => "error",
+      Severity::Warning => "warning",
+    };
+    format!(
+      "{}: {} ({}, {})",
+      level, self.message, self.filename, self.code
+    )
+  }
+}
@@ -0,0 +1,45 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::diagnostic::LintDiagnostic;
+use swc_common::SourceFile;
+
+/// Applies each diagnostic's *first* `LintFix`, if any, to `source_file`'s
+/// text. A diagnostic's `fixes` are alternative suggestions, not a combined
+/// patch set — only one can ever be applied to a given occurrence, so we
+/// always take `fixes[0]` rather than flattening every alternative's
+/// changes together. Returns the patched source and whether anything
+/// changed.
+pub fn apply_fixes(
+  source_file: &SourceFile,
+  diagnostics: &[LintDiagnostic],
+) -> (String, bool) {
+  let source = source_file.src.as_str();
+  let base = source_file.start_pos;
+
+  let mut changes: Vec<&crate::diagnostic::TextChange> = diagnostics
+    .iter()
+    .filter_map(|d| d.fixes.first())
+    .flat_map(|fix| fix.changes.iter())
+    .collect();
+  changes.sort_by_key(|c| c.span.lo());
+
+  let mut result = String::with_capacity(source.len());
+  let mut cursor = base.0;
+  let mut changed = false;
+
+  for change in changes {
+    let lo = change.span.lo().0;
+    let hi = change.span.hi().0;
+    if lo < cursor {
+      // Overlaps a change we already applied; skip it rather than produce
+      // a corrupted result.
+      continue;
+    }
+    result.push_str(&source[(cursor - base.0) as usize..(lo - base.0) as usize]);
+    result.push_str(&change.new_text);
+    cursor = hi;
+    changed = true;
+  }
+  result.push_str(&source[(cursor - base.0) as usize..]);
+
+  (result, changed)
+}
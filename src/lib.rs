@@ -0,0 +1,15 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+pub mod config;
+pub mod context;
+pub mod diagnostic;
+pub mod fixer;
+pub mod linter;
+pub mod rules;
+
+#[cfg(test)]
+pub mod test_util;
+
+pub use config::LintConfig;
+pub use context::Context;
+pub use diagnostic::{LintDiagnostic, LintFix, TextChange};
+pub use rules::{LintRule, ProgramRef, Severity};
@@ -0,0 +1,52 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::config::LintConfig;
+use crate::context::Context;
+use crate::diagnostic::LintDiagnostic;
+use crate::fixer::apply_fixes;
+use crate::rules::{get_all_rules, ProgramRef, Severity};
+use swc_common::SourceFile;
+use swc_ecmascript::ast::Program;
+
+/// Runs every registered rule over `source_file`'s `program` and returns the
+/// diagnostics they raised, in rule-registration order. `config` is
+/// extended with any `// deno-lint-level` directives found in the source
+/// before linting starts, so inline comments affect this run's output the
+/// same way a config file would.
+pub fn lint_program(
+  source_file: &SourceFile,
+  program: &Program,
+  config: LintConfig,
+) -> Vec<LintDiagnostic> {
+  let file_name = source_file.name.to_string();
+  let config = config.with_inline_directives(&source_file.src, source_file.start_pos);
+  let rules = get_all_rules();
+  let default_severities: std::collections::HashMap<&'static str, Severity> = rules
+    .iter()
+    .map(|rule| (rule.code(), rule.default_severity()))
+    .collect();
+
+  let mut context = Context::new(file_name, config, default_severities);
+  let program_ref = ProgramRef::from(program);
+  for rule in rules {
+    rule.lint_program(&mut context, program_ref);
+  }
+  context.diagnostics().to_vec()
+}
+
+/// Lints `program` and, if any diagnostic carries a fix, applies it to
+/// `source_file`'s text. Returns the (possibly unchanged) fixed source.
+pub fn fix_file(source_file: &SourceFile, program: &Program, config: LintConfig) -> String {
+  let diagnostics = lint_program(source_file, program, config);
+  let (fixed, _changed) = apply_fixes(source_file, &diagnostics);
+  fixed
+}
+
+/// The process exit code a CLI should use: non-zero iff any diagnostic
+/// fired at `Severity::Error`.
+pub fn exit_code(diagnostics: &[LintDiagnostic]) -> i32 {
+  if diagnostics.iter().any(|d| d.severity == Severity::Error) {
+    1
+  } else {
+    0
+  }
+}
@@ -0,0 +1,62 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::context::Context;
+use swc_ecmascript::ast::{Module, Script};
+use swc_ecmascript::visit::Node;
+
+mod no_redeclare;
+mod no_setter_return;
+
+pub use no_redeclare::NoRedeclare;
+pub use no_setter_return::NoSetterReturn;
+
+/// A borrowed `Program`: either a full module or a plain script. Rules
+/// match on it to start their visitor with `visit_module`/`visit_script`
+/// (or `visit_with`) on the inner node.
+#[derive(Clone, Copy)]
+pub enum ProgramRef<'a> {
+  Module(&'a Module),
+  Script(&'a Script),
+}
+
+impl<'a> From<&'a swc_ecmascript::ast::Program> for ProgramRef<'a> {
+  fn from(program: &'a swc_ecmascript::ast::Program) -> Self {
+    match program {
+      swc_ecmascript::ast::Program::Module(m) => ProgramRef::Module(m),
+      swc_ecmascript::ast::Program::Script(s) => ProgramRef::Script(s),
+    }
+  }
+}
+
+/// Placeholder parent node passed to the root-level `visit_module`/
+/// `visit_script` call, since a `Program` has no real parent node of its
+/// own.
+pub struct DummyNode;
+impl Node for DummyNode {}
+pub const DUMMY_NODE: DummyNode = DummyNode;
+
+/// The severity a diagnostic is reported at: a rule's default, or the level
+/// a config map or inline directive resolves it to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+  Warning,
+  Error,
+}
+
+pub trait LintRule {
+  fn new() -> Box<Self>
+  where
+    Self: Sized;
+  fn tags(&self) -> &'static [&'static str];
+  fn code(&self) -> &'static str;
+  fn lint_program(&self, context: &mut Context, program: ProgramRef<'_>);
+
+  /// The severity this rule's diagnostics are reported at unless a config
+  /// map or inline directive says otherwise.
+  fn default_severity(&self) -> Severity {
+    Severity::Warning
+  }
+}
+
+pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
+  vec![NoRedeclare::new(), NoSetterReturn::new()]
+}
@@ -1,13 +1,13 @@
 // Copyright 2020 the Deno authors. All rights reserved. MIT license.
-use super::Context;
-use super::LintRule;
+use super::{Context, LintRule, ProgramRef, Severity, DUMMY_NODE};
+use swc_common::Span;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::{
   ast::*, utils::find_ids, utils::ident::IdentLike, utils::Id, visit::Node,
   visit::Visit, visit::VisitWith,
 };
 
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 pub struct NoRedeclare;
 
@@ -16,7 +16,7 @@ impl LintRule for NoRedeclare {
     Box::new(NoRedeclare)
   }
 
-  fn tags(&self) -> &[&'static str] {
+  fn tags(&self) -> &'static [&'static str] {
     &["recommended"]
   }
 
@@ -24,31 +24,46 @@ impl LintRule for NoRedeclare {
     "no-redeclare"
   }
 
-  fn lint_program(&self, context: &mut Context, program: &Program) {
+  fn default_severity(&self) -> Severity {
+    Severity::Error
+  }
+
+  fn lint_program(&self, context: &mut Context, program: ProgramRef<'_>) {
     let mut visitor = NoRedeclareVisitor {
       context,
       bindings: Default::default(),
     };
-    program.visit_with(program, &mut visitor);
+    match program {
+      ProgramRef::Module(ref m) => m.visit_with(&DUMMY_NODE, &mut visitor),
+      ProgramRef::Script(ref s) => s.visit_with(&DUMMY_NODE, &mut visitor),
+    }
   }
 }
 
 struct NoRedeclareVisitor<'c> {
   context: &'c mut Context,
-  /// TODO(kdy1): Change this to HashMap<Id, Vec<Span>> and use those spans to point previous bindings/
-  bindings: HashSet<Id>,
+  bindings: HashMap<Id, Span>,
 }
 
 impl<'c> NoRedeclareVisitor<'c> {
   fn declare(&mut self, i: &Ident) {
     let id = i.to_id();
 
-    if !self.bindings.insert(id) {
-      self.context.add_diagnostic(
-        i.span,
-        "no-redeclare",
-        "Redeclaration is not allowed",
-      );
+    match self.bindings.get(&id) {
+      Some(prev_span) => {
+        self.context.add_diagnostic_with_hint(
+          i.span,
+          "no-redeclare",
+          "Redeclaration is not allowed",
+          vec![(
+            *prev_span,
+            format!("'{}' was already declared here", id.0),
+          )],
+        );
+      }
+      None => {
+        self.bindings.insert(id, i.span);
+      }
     }
   }
 }
@@ -196,4 +211,27 @@ mod tests {
       38,
     );
   }
+
+  #[test]
+  fn no_redeclare_invalid_points_at_original_binding() {
+    assert_lint_err_with_label::<NoRedeclare>(
+      "var a = 3; var a = 10;",
+      15,
+      1,
+      4,
+      "'a' was already declared here",
+    );
+  }
+
+  #[test]
+  fn no_redeclare_inline_directive_turns_the_rule_off() {
+    let diagnostics = crate::test_util::lint::<NoRedeclare>(
+      "// deno-lint-level no-redeclare:off\nvar a = 3; var a = 10;",
+    );
+    assert!(
+      diagnostics.is_empty(),
+      "expected the inline directive to suppress the redeclaration diagnostic, got {:?}",
+      diagnostics
+    );
+  }
 }
@@ -1,11 +1,15 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
-use super::{Context, LintRule, ProgramRef, DUMMY_NODE};
+use super::{Context, LintRule, ProgramRef, Severity, DUMMY_NODE};
+use crate::diagnostic::{LintFix, TextChange};
+use swc_ecmascript::ast::ArrowExpr;
 use swc_ecmascript::ast::BlockStmt;
 use swc_ecmascript::ast::Class;
 use swc_ecmascript::ast::ClassMember;
+use swc_ecmascript::ast::Function;
+use swc_ecmascript::ast::GetterProp;
 use swc_ecmascript::ast::MethodKind;
+use swc_ecmascript::ast::ReturnStmt;
 use swc_ecmascript::ast::SetterProp;
-use swc_ecmascript::ast::Stmt;
 use swc_ecmascript::visit::noop_visit_type;
 use swc_ecmascript::visit::Node;
 use swc_ecmascript::visit::Visit;
@@ -25,6 +29,10 @@ impl LintRule for NoSetterReturn {
     "no-setter-return"
   }
 
+  fn default_severity(&self) -> Severity {
+    Severity::Error
+  }
+
   fn lint_program(&self, context: &mut Context, program: ProgramRef<'_>) {
     let mut visitor = NoSetterReturnVisitor::new(context);
     match program {
@@ -43,18 +51,9 @@ impl<'c> NoSetterReturnVisitor<'c> {
     Self { context }
   }
 
-  fn check_block_stmt(&mut self, block_stmt: &BlockStmt) {
-    for stmt in &block_stmt.stmts {
-      if let Stmt::Return(return_stmt) = stmt {
-        if return_stmt.arg.is_some() {
-          self.context.add_diagnostic(
-            return_stmt.span,
-            "no-setter-return",
-            "Setter cannot return a value",
-          );
-        }
-      }
-    }
+  fn check_setter_body(&mut self, block_stmt: &BlockStmt) {
+    let mut visitor = SetterBodyVisitor::new(self.context);
+    visitor.visit_block_stmt(block_stmt, &DUMMY_NODE);
   }
 }
 
@@ -67,14 +66,14 @@ impl<'c> Visit for NoSetterReturnVisitor<'c> {
         ClassMember::Method(class_method) => {
           if class_method.kind == MethodKind::Setter {
             if let Some(block_stmt) = &class_method.function.body {
-              self.check_block_stmt(block_stmt);
+              self.check_setter_body(block_stmt);
             }
           }
         }
         ClassMember::PrivateMethod(private_method) => {
           if private_method.kind == MethodKind::Setter {
             if let Some(block_stmt) = &private_method.function.body {
-              self.check_block_stmt(block_stmt);
+              self.check_setter_body(block_stmt);
             }
           }
         }
@@ -89,11 +88,71 @@ impl<'c> Visit for NoSetterReturnVisitor<'c> {
     _parent: &dyn Node,
   ) {
     if let Some(block_stmt) = &setter_prop.body {
-      self.check_block_stmt(block_stmt);
+      self.check_setter_body(block_stmt);
     }
   }
 }
 
+/// Walks the full body of a setter, reporting every `return <expr>;` it
+/// finds while stopping at the boundary of any nested function, arrow
+/// function, class, or getter/setter property, since returns in those
+/// belong to a different scope.
+struct SetterBodyVisitor<'c> {
+  context: &'c mut Context,
+}
+
+impl<'c> SetterBodyVisitor<'c> {
+  fn new(context: &'c mut Context) -> Self {
+    Self { context }
+  }
+}
+
+impl<'c> Visit for SetterBodyVisitor<'c> {
+  noop_visit_type!();
+
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt, _parent: &dyn Node) {
+    if return_stmt.arg.is_some() {
+      // Replace the whole `return <expr>;` with a bare `return;` rather
+      // than just deleting the argument span, so we don't leave the space
+      // between `return` and the argument behind.
+      let fix = LintFix {
+        description: "Remove the returned value".to_string(),
+        changes: vec![TextChange {
+          span: return_stmt.span,
+          new_text: "return;".to_string(),
+        }],
+      };
+      self.context.add_diagnostic_with_fixes(
+        return_stmt.span,
+        "no-setter-return",
+        "Setter cannot return a value",
+        vec![fix],
+      );
+    }
+  }
+
+  fn visit_function(&mut self, _function: &Function, _parent: &dyn Node) {
+    // A nested function has its own return boundary.
+  }
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &ArrowExpr, _parent: &dyn Node) {
+    // Arrow functions have their own return boundary too.
+  }
+
+  fn visit_class(&mut self, _class: &Class, _parent: &dyn Node) {
+    // Methods declared on a nested class are out of scope for this setter.
+  }
+
+  fn visit_getter_prop(&mut self, _getter_prop: &GetterProp, _parent: &dyn Node) {
+    // Object-literal getters/setters aren't wrapped in a `Function` node,
+    // so they need their own explicit boundary.
+  }
+
+  fn visit_setter_prop(&mut self, _setter_prop: &SetterProp, _parent: &dyn Node) {
+    // Same as `visit_getter_prop`: a nested setter is a different scope.
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -119,4 +178,98 @@ class b {
       vec![(4, 4), (7, 4)],
     );
   }
+
+  #[test]
+  fn no_setter_return_invalid_nested() {
+    assert_lint_err_on_line_n::<NoSetterReturn>(
+      r#"
+class a {
+  set setterA(a) {
+    if (a) {
+      return 5;
+    }
+  }
+}
+      "#,
+      vec![(5, 6)],
+    );
+    assert_lint_err_on_line_n::<NoSetterReturn>(
+      r#"
+class a {
+  set setterA(a) {
+    for (let i = 0; i < a; i++) {
+      return 5;
+    }
+  }
+}
+      "#,
+      vec![(5, 6)],
+    );
+    assert_lint_err_on_line_n::<NoSetterReturn>(
+      r#"
+class a {
+  set setterA(a) {
+    try {
+      return 5;
+    } catch (e) {
+      return 6;
+    }
+  }
+}
+      "#,
+      vec![(5, 6), (7, 6)],
+    );
+    assert_lint_err_on_line_n::<NoSetterReturn>(
+      r#"
+class a {
+  set setterA(a) {
+    switch (a) {
+      case 1:
+        return 5;
+    }
+  }
+}
+      "#,
+      vec![(6, 8)],
+    );
+    assert_lint_err_on_line_n::<NoSetterReturn>(
+      r#"
+class a {
+  set setterA(a) {
+    while (a) {
+      return 5;
+    }
+  }
+}
+      "#,
+      vec![(5, 6)],
+    );
+    assert_lint_err_on_line_n::<NoSetterReturn>(
+      r#"
+class a {
+  set setterA(a) {
+    const o = {
+      get foo() {
+        return 1;
+      },
+    };
+    return o.foo;
+  }
+}
+      "#,
+      vec![(9, 4)],
+    );
+  }
+
+  #[test]
+  fn no_setter_return_valid_nested() {
+    assert_lint_ok! {
+      NoSetterReturn,
+      "class a { set setterA(a) { if (a) { function f() { return 1; } } } }",
+      "class a { set setterA(a) { const f = () => { return 1; }; } }",
+      "class a { set setterA(a) { class b { get c() { return 1; } } } }",
+      "class a { set setterA(a) { const o = { get foo() { return 1; } }; } }",
+      "class a { set setterA(a) { const o = { set foo(v) { return; } }; } }",
+    };
+  }
 }
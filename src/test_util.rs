@@ -0,0 +1,171 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::config::LintConfig;
+use crate::context::Context;
+use crate::rules::{LintRule, ProgramRef};
+use std::collections::HashMap;
+use swc_common::comments::SingleThreadedComments;
+use swc_common::sync::Lrc;
+use swc_common::FileName;
+use swc_common::SourceFile;
+use swc_common::SourceMap;
+use swc_ecmascript::ast::Program;
+use swc_ecmascript::parser::lexer::Lexer;
+use swc_ecmascript::parser::Parser;
+use swc_ecmascript::parser::StringInput;
+use swc_ecmascript::parser::Syntax;
+use swc_ecmascript::parser::TsConfig;
+
+pub fn parse(source_code: &str) -> (Lrc<SourceFile>, Program) {
+  let source_map = SourceMap::default();
+  let source_file = source_map.new_source_file(
+    FileName::Custom("lint_test.ts".to_string()),
+    source_code.to_string(),
+  );
+  let comments = SingleThreadedComments::default();
+  let syntax = Syntax::Typescript(TsConfig {
+    tsx: true,
+    dynamic_import: true,
+    decorators: true,
+    ..Default::default()
+  });
+  let lexer = Lexer::new(
+    syntax,
+    Default::default(),
+    StringInput::from(&*source_file),
+    Some(&comments),
+  );
+  let mut parser = Parser::new_from(lexer);
+  let program = parser
+    .parse_program()
+    .expect("should parse module or script");
+  (source_file, program)
+}
+
+/// Lints `source_code` with a single rule `T`, resolving severity (and
+/// inline `// deno-lint-level` directives) the same way `linter::lint_program`
+/// does for a full run.
+pub fn lint<T: LintRule + 'static>(source_code: &str) -> Vec<crate::diagnostic::LintDiagnostic> {
+  let (source_file, program) = parse(source_code);
+  let rule = T::new();
+  let config =
+    LintConfig::default().with_inline_directives(&source_file.src, source_file.start_pos);
+  let mut default_severities = HashMap::new();
+  default_severities.insert(rule.code(), rule.default_severity());
+
+  let mut context = Context::new(
+    source_file.name.to_string(),
+    config,
+    default_severities,
+  );
+  rule.lint_program(&mut context, ProgramRef::from(&program));
+  context.diagnostics().to_vec()
+}
+
+macro_rules! assert_lint_ok {
+  ($rule:ident, $($source:expr,)*) => {
+    $(
+      let diagnostics = crate::test_util::lint::<$rule>($source);
+      assert!(
+        diagnostics.is_empty(),
+        "expected no diagnostics for {:?}, got {:?}",
+        $source,
+        diagnostics,
+      );
+    )*
+  };
+}
+pub(crate) use assert_lint_ok;
+
+pub fn assert_lint_err<T: LintRule + 'static>(source: &str, col: usize) {
+  assert_lint_err_n::<T>(source, vec![col]);
+}
+
+pub fn assert_lint_err_n<T: LintRule + 'static>(source: &str, cols: Vec<usize>) {
+  assert_lint_err_on_line_n::<T>(
+    source,
+    cols.into_iter().map(|col| (1, col)).collect(),
+  );
+}
+
+pub fn assert_lint_err_on_line<T: LintRule + 'static>(
+  source: &str,
+  line: usize,
+  col: usize,
+) {
+  assert_lint_err_on_line_n::<T>(source, vec![(line, col)]);
+}
+
+/// Asserts a single diagnostic at column `col` whose first label has the
+/// text `label` and starts at (`label_line`, `label_col`).
+pub fn assert_lint_err_with_label<T: LintRule + 'static>(
+  source: &str,
+  col: usize,
+  label_line: usize,
+  label_col: usize,
+  label: &str,
+) {
+  let diagnostics = lint::<T>(source);
+  assert_eq!(
+    diagnostics.len(),
+    1,
+    "expected exactly one diagnostic, got {:?}",
+    diagnostics
+  );
+  let diagnostic = &diagnostics[0];
+
+  let source_map = SourceMap::default();
+  source_map.new_source_file(
+    FileName::Custom("lint_test.ts".to_string()),
+    source.to_string(),
+  );
+
+  let loc = source_map.lookup_char_pos(diagnostic.range.lo());
+  assert_eq!(loc.col_display, col, "wrong column for {:?}", diagnostic);
+
+  let (label_span, label_text) = diagnostic
+    .labels
+    .first()
+    .unwrap_or_else(|| panic!("expected a label on {:?}", diagnostic));
+  assert_eq!(label_text, label, "wrong label text for {:?}", diagnostic);
+
+  let label_loc = source_map.lookup_char_pos(label_span.lo());
+  assert_eq!(label_loc.line, label_line, "wrong label line for {:?}", diagnostic);
+  assert_eq!(
+    label_loc.col_display, label_col,
+    "wrong label column for {:?}",
+    diagnostic
+  );
+}
+
+pub fn assert_lint_err_on_line_n<T: LintRule + 'static>(
+  source: &str,
+  error_locations: Vec<(usize, usize)>,
+) {
+  let diagnostics = lint::<T>(source);
+  assert_eq!(
+    diagnostics.len(),
+    error_locations.len(),
+    "expected {} diagnostics, got {}: {:?}",
+    error_locations.len(),
+    diagnostics.len(),
+    diagnostics,
+  );
+
+  let source_map = SourceMap::default();
+  source_map.new_source_file(
+    FileName::Custom("lint_test.ts".to_string()),
+    source.to_string(),
+  );
+
+  for (diagnostic, (expected_line, expected_col)) in
+    diagnostics.iter().zip(error_locations)
+  {
+    let loc = source_map.lookup_char_pos(diagnostic.range.lo());
+    assert_eq!(loc.line, expected_line, "wrong line for {:?}", diagnostic);
+    assert_eq!(
+      loc.col_display, expected_col,
+      "wrong column for {:?}",
+      diagnostic
+    );
+  }
+}